@@ -4,17 +4,45 @@ use std::time::{Duration, Instant};
 use std::thread;
 use windows::Win32::UI::Input::KeyboardAndMouse::{
     mouse_event, MOUSEEVENTF_LEFTDOWN, MOUSEEVENTF_LEFTUP,
-    MOUSEEVENTF_RIGHTDOWN, MOUSEEVENTF_RIGHTUP, VK_F1, VK_F2, VK_F3, VK_F4,
+    MOUSEEVENTF_RIGHTDOWN, MOUSEEVENTF_RIGHTUP, MOUSE_EVENT_FLAGS, VK_F1, VK_F2, VK_F3, VK_F4,
     VK_F5, VK_F6, VK_F7, VK_F8, VK_F9, VK_F10, VK_F11, VK_F12,
-    GetAsyncKeyState, VK_MENU, VK_CONTROL, VK_SHIFT
+    GetAsyncKeyState, VK_MENU, VK_CONTROL, VK_SHIFT,
+    SendInput, INPUT, INPUT_0, INPUT_MOUSE, MOUSEINPUT,
+    keybd_event, KEYEVENTF_KEYUP, KEYBD_EVENT_FLAGS, VK_RETURN, VK_ESCAPE, VK_SPACE, VK_TAB
 };
-use windows::Win32::UI::WindowsAndMessaging::SetCursorPos;
-use windows::Win32::System::Registry::{RegOpenKeyExW, RegQueryValueExW, HKEY_CURRENT_USER, KEY_READ, HKEY};
-use windows::Win32::Foundation::ERROR_SUCCESS;
+use windows::Win32::UI::WindowsAndMessaging::{
+    SetCursorPos, GetCursorPos, MessageBeep, MB_ICONASTERISK,
+    GetWindowLongPtrW, SetWindowLongPtrW, SetLayeredWindowAttributes,
+    GWL_EXSTYLE, WS_EX_LAYERED, WS_EX_TRANSPARENT, LWA_ALPHA
+};
+use raw_window_handle::{HasWindowHandle, RawWindowHandle};
+use windows::Win32::System::Registry::{
+    RegOpenKeyExW, RegQueryValueExW, RegCloseKey, RegNotifyChangeKeyValue,
+    HKEY_CURRENT_USER, KEY_READ, KEY_NOTIFY, HKEY, REG_NOTIFY_CHANGE_LAST_SET
+};
+use windows::Win32::Foundation::{ERROR_SUCCESS, BOOL, HANDLE, POINT, RECT, LPARAM, HWND, COLORREF};
+use windows::Win32::Graphics::Gdi::{
+    EnumDisplayMonitors, MonitorFromPoint, GetMonitorInfoW, HDC, HMONITOR, MONITORINFO, MONITOR_DEFAULTTONULL
+};
+use windows::Win32::UI::HiDpi::{GetDpiForMonitor, MDT_EFFECTIVE_DPI};
 use windows::core::HSTRING;
 use std::ptr;
+use std::fs;
+use std::path::PathBuf;
+use std::collections::{HashMap, VecDeque};
 
 const HOTKEY_POLL_INTERVAL_MS: u64 = 50; // Increased to 50ms for more reliable detection
+const MAIN_WINDOW_SIZE: [f32; 2] = [560.0, 320.0];
+const TOOLBAR_WINDOW_SIZE: [f32; 2] = [250.0, 36.0];
+const TOOLBAR_MIN_OPACITY: f32 = 0.2; // Keeps the toolbar from disappearing entirely
+const POSITION_CAPTURE_DELAY_SECS: u64 = 3; // Time to move the mouse to the target before capture
+const RATE_WINDOW_SECS: u64 = 60; // Click timestamps kept for the live rate readout
+const WATCHDOG_GRACE_SECS: u64 = 5; // Slack past the expected step time before the clicking thread counts as stuck
+const BENCHMARK_INTERVALS_MS: [u64; 6] = [50, 20, 10, 5, 2, 1]; // Tried from slowest to fastest
+const BENCHMARK_TOLERANCE: f64 = 0.10; // Mean achieved interval may overshoot the target by this fraction
+const CUMULATIVE_SAVE_INTERVAL_SECS: u64 = 5; // How often the running total is written while clicking
+const THEME_RECHECK_INTERVAL_SECS: u64 = 5; // Fallback re-read in case change notifications are missed
+const THEME_REGISTRY_KEY: &str = "SOFTWARE\\Microsoft\\Windows\\CurrentVersion\\Themes\\Personalize";
 
 #[derive(Clone, Copy, PartialEq)]
 enum MouseButton {
@@ -22,6 +50,13 @@ enum MouseButton {
     Right,
 }
 
+#[derive(Clone, Copy, PartialEq)]
+enum CursorMode {
+    Current,
+    Fixed,
+    List, // Cycle through the saved positions list
+}
+
 #[derive(Clone, Copy, PartialEq)]
 enum ClickMode {
     RepeatCount(u32),
@@ -35,6 +70,13 @@ enum Theme {
     Dark,
 }
 
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+enum RateUnit {
+    #[default]
+    Cps,
+    Cpm,
+}
+
 #[derive(Clone, Copy, PartialEq, Debug)]
 enum ModifierKey {
     None,
@@ -49,6 +91,22 @@ enum FunctionKey {
     F1, F2, F3, F4, F5, F6, F7, F8, F9, F10, F11, F12,
 }
 
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum HookKey {
+    Enter,
+    Escape,
+    Space,
+    Tab,
+}
+
+// One step of an on-start / on-stop hook
+#[derive(Clone, Copy, PartialEq)]
+enum HookAction {
+    PressKey(HookKey),
+    Click(MouseButton),
+    Wait(u32),
+}
+
 impl ModifierKey {
     fn is_pressed(&self) -> bool {
         unsafe {
@@ -103,10 +161,173 @@ impl FunctionKey {
     }
 }
 
-// Function to detect Windows dark mode
-fn is_windows_dark_mode() -> bool {
+impl HookKey {
+    fn press(&self) {
+        let vk_code = match self {
+            HookKey::Enter => VK_RETURN.0,
+            HookKey::Escape => VK_ESCAPE.0,
+            HookKey::Space => VK_SPACE.0,
+            HookKey::Tab => VK_TAB.0,
+        } as u8;
+        unsafe {
+            keybd_event(vk_code, 0, KEYBD_EVENT_FLAGS(0), 0);
+            keybd_event(vk_code, 0, KEYEVENTF_KEYUP, 0);
+        }
+    }
+}
+
+impl HookAction {
+    fn kind_name(&self) -> &'static str {
+        match self {
+            HookAction::PressKey(_) => "Press key",
+            HookAction::Click(_) => "Click",
+            HookAction::Wait(_) => "Wait",
+        }
+    }
+    
+    fn run(&self) {
+        match self {
+            HookAction::PressKey(key) => key.press(),
+            HookAction::Click(button) => unsafe {
+                match button {
+                    MouseButton::Left => {
+                        let _ = mouse_event(MOUSEEVENTF_LEFTDOWN, 0, 0, 0, 0);
+                        let _ = mouse_event(MOUSEEVENTF_LEFTUP, 0, 0, 0, 0);
+                    }
+                    MouseButton::Right => {
+                        let _ = mouse_event(MOUSEEVENTF_RIGHTDOWN, 0, 0, 0, 0);
+                        let _ = mouse_event(MOUSEEVENTF_RIGHTUP, 0, 0, 0, 0);
+                    }
+                }
+            },
+            HookAction::Wait(ms) => thread::sleep(Duration::from_millis(*ms as u64)),
+        }
+    }
+}
+
+// Time the Wait steps of a hook add up to, for the watchdog
+fn hook_actions_duration(actions: &[HookAction]) -> Duration {
+    actions.iter()
+        .map(|action| match action {
+            HookAction::Wait(ms) => Duration::from_millis(*ms as u64),
+            _ => Duration::ZERO,
+        })
+        .sum()
+}
+
+fn run_hook_actions(actions: &[HookAction]) {
+    for action in actions {
+        action.run();
+    }
+}
+
+// Small inline editor for an on-start / on-stop action list
+fn edit_hook_actions(ui: &mut egui::Ui, id: &str, actions: &mut Vec<HookAction>) {
+    let mut remove_index = None;
+    
+    for (i, action) in actions.iter_mut().enumerate() {
+        ui.horizontal(|ui| {
+            egui::ComboBox::from_id_source((id, i, "kind"))
+                .selected_text(action.kind_name())
+                .show_ui(ui, |ui| {
+                    if ui.selectable_label(matches!(action, HookAction::PressKey(_)), "Press key").clicked()
+                        && !matches!(action, HookAction::PressKey(_)) {
+                        *action = HookAction::PressKey(HookKey::Enter);
+                    }
+                    if ui.selectable_label(matches!(action, HookAction::Click(_)), "Click").clicked()
+                        && !matches!(action, HookAction::Click(_)) {
+                        *action = HookAction::Click(MouseButton::Left);
+                    }
+                    if ui.selectable_label(matches!(action, HookAction::Wait(_)), "Wait").clicked()
+                        && !matches!(action, HookAction::Wait(_)) {
+                        *action = HookAction::Wait(100);
+                    }
+                });
+            
+            match action {
+                HookAction::PressKey(key) => {
+                    egui::ComboBox::from_id_source((id, i, "key"))
+                        .selected_text(format!("{:?}", key))
+                        .show_ui(ui, |ui| {
+                            ui.selectable_value(key, HookKey::Enter, "Enter");
+                            ui.selectable_value(key, HookKey::Escape, "Escape");
+                            ui.selectable_value(key, HookKey::Space, "Space");
+                            ui.selectable_value(key, HookKey::Tab, "Tab");
+                        });
+                }
+                HookAction::Click(button) => {
+                    egui::ComboBox::from_id_source((id, i, "button"))
+                        .selected_text(match button {
+                            MouseButton::Left => "Left",
+                            MouseButton::Right => "Right",
+                        })
+                        .show_ui(ui, |ui| {
+                            ui.selectable_value(button, MouseButton::Left, "Left");
+                            ui.selectable_value(button, MouseButton::Right, "Right");
+                        });
+                }
+                HookAction::Wait(ms) => {
+                    ui.add(egui::DragValue::new(ms).suffix("ms").range(0..=60000).speed(10));
+                }
+            }
+            
+            if ui.small_button("✖").clicked() {
+                remove_index = Some(i);
+            }
+        });
+    }
+    
+    if let Some(i) = remove_index {
+        actions.remove(i);
+    }
+    
+    if ui.small_button("+ Add action").clicked() {
+        actions.push(HookAction::PressKey(HookKey::Enter));
+    }
+}
+
+// The four mouse events of a double-click, all at the current cursor position
+fn double_click_inputs(button: MouseButton) -> [INPUT; 4] {
+    let (down, up) = match button {
+        MouseButton::Left => (MOUSEEVENTF_LEFTDOWN, MOUSEEVENTF_LEFTUP),
+        MouseButton::Right => (MOUSEEVENTF_RIGHTDOWN, MOUSEEVENTF_RIGHTUP),
+    };
+    
+    let make_input = |flags: MOUSE_EVENT_FLAGS| INPUT {
+        r#type: INPUT_MOUSE,
+        Anonymous: INPUT_0 {
+            mi: MOUSEINPUT {
+                dx: 0,
+                dy: 0,
+                mouseData: 0,
+                dwFlags: flags,
+                time: 0,
+                dwExtraInfo: 0,
+            },
+        },
+    };
+    [make_input(down), make_input(up), make_input(down), make_input(up)]
+}
+
+// Send a double-click as one atomic SendInput batch so the OS double-click
+// detection sees both clicks at the same coordinate within GetDoubleClickTime():
+// every event has time 0, so the system stamps them back to back at injection,
+// and none carries MOUSEEVENTF_MOVE/ABSOLUTE, so the cursor stays put between them
+fn send_os_double_click(button: MouseButton) {
+    let inputs = double_click_inputs(button);
+    
     unsafe {
-        let key_name = HSTRING::from("SOFTWARE\\Microsoft\\Windows\\CurrentVersion\\Themes\\Personalize");
+        let sent = SendInput(&inputs, std::mem::size_of::<INPUT>() as i32);
+        if sent as usize != inputs.len() {
+            println!("SendInput injected only {} of {} events", sent, inputs.len()); // Debug
+        }
+    }
+}
+
+// Function to detect Windows dark mode, None if the registry can't be read
+fn is_windows_dark_mode() -> Option<bool> {
+    unsafe {
+        let key_name = HSTRING::from(THEME_REGISTRY_KEY);
         let mut hkey: HKEY = HKEY(ptr::null_mut());
         
         let result = RegOpenKeyExW(
@@ -118,7 +339,8 @@ fn is_windows_dark_mode() -> bool {
         );
         
         if result != ERROR_SUCCESS {
-            return false; // Default to light mode if can't read
+            println!("Failed to open theme registry key: {:?}", result); // Debug
+            return None;
         }
         
         let value_name = HSTRING::from("AppsUseLightTheme");
@@ -134,11 +356,329 @@ fn is_windows_dark_mode() -> bool {
             Some(&mut data_size)
         );
         
+        let _ = RegCloseKey(hkey);
+        
         if result == ERROR_SUCCESS {
             // 0 means dark mode, 1 means light mode
-            data == 0
+            Some(data == 0)
         } else {
-            false // Default to light mode
+            println!("Failed to read AppsUseLightTheme: {:?}", result); // Debug
+            None
+        }
+    }
+}
+
+// Watch the theme registry key (the value Windows updates before broadcasting
+// WM_SETTINGCHANGE) and raise `changed` so the UI re-reads it right away
+fn spawn_theme_change_watcher(changed: Arc<Mutex<bool>>) {
+    thread::spawn(move || unsafe {
+        let key_name = HSTRING::from(THEME_REGISTRY_KEY);
+        let mut hkey: HKEY = HKEY(ptr::null_mut());
+        
+        let result = RegOpenKeyExW(HKEY_CURRENT_USER, &key_name, 0, KEY_NOTIFY, &mut hkey);
+        if result != ERROR_SUCCESS {
+            println!("Theme change watcher unavailable: {:?}", result); // Debug
+            return;
+        }
+        
+        loop {
+            // Blocks until a value under the key changes
+            let result = RegNotifyChangeKeyValue(
+                hkey,
+                BOOL::from(false),
+                REG_NOTIFY_CHANGE_LAST_SET,
+                HANDLE(ptr::null_mut()),
+                BOOL::from(false)
+            );
+            if result != ERROR_SUCCESS {
+                println!("Theme change watcher stopped: {:?}", result); // Debug
+                break;
+            }
+            *changed.lock().unwrap() = true;
+        }
+        
+        let _ = RegCloseKey(hkey);
+    });
+}
+
+// Settings that survive restarts, stored as key=value lines in %APPDATA%\nclicker
+#[derive(Clone, Default)]
+struct PersistedSettings {
+    last_dark_mode: Option<bool>,
+    positions: Vec<(i32, i32)>,
+    position_sets: Vec<(String, Vec<(i32, i32)>)>,
+    rate_unit: RateUnit,
+    cumulative_clicks: u64, // Clicks across all sessions since the last reset
+    cumulative_target_enabled: bool,
+    cumulative_target: u64,
+}
+
+fn format_positions(positions: &[(i32, i32)]) -> String {
+    positions.iter()
+        .map(|(x, y)| format!("{},{}", x, y))
+        .collect::<Vec<_>>()
+        .join(";")
+}
+
+fn parse_positions(value: &str) -> Vec<(i32, i32)> {
+    value.split(';')
+        .filter_map(|pair| {
+            let (x, y) = pair.split_once(',')?;
+            Some((x.trim().parse().ok()?, y.trim().parse().ok()?))
+        })
+        .collect()
+}
+
+impl PersistedSettings {
+    fn path() -> Option<PathBuf> {
+        std::env::var_os("APPDATA").map(|dir| PathBuf::from(dir).join("nclicker").join("settings.cfg"))
+    }
+    
+    fn load() -> Self {
+        let mut settings = Self::default();
+        let Some(path) = Self::path() else {
+            return settings;
+        };
+        let Ok(contents) = fs::read_to_string(&path) else {
+            return settings;
+        };
+        
+        for line in contents.lines() {
+            let Some((key, value)) = line.split_once('=') else {
+                continue;
+            };
+            match key.trim() {
+                "last_dark_mode" => settings.last_dark_mode = value.trim().parse().ok(),
+                "positions" => settings.positions = parse_positions(value),
+                "rate_unit" => {
+                    settings.rate_unit = if value.trim() == "cpm" { RateUnit::Cpm } else { RateUnit::Cps };
+                }
+                "cumulative_clicks" => settings.cumulative_clicks = value.trim().parse().unwrap_or(0),
+                "cumulative_target_enabled" => settings.cumulative_target_enabled = value.trim().parse().unwrap_or(false),
+                "cumulative_target" => settings.cumulative_target = value.trim().parse().unwrap_or(0),
+                key => {
+                    if let Some(name) = key.strip_prefix("position_set.") {
+                        settings.position_sets.push((name.to_string(), parse_positions(value)));
+                    }
+                }
+            }
+        }
+        settings
+    }
+    
+    fn save(&self) {
+        let Some(path) = Self::path() else {
+            return;
+        };
+        
+        let mut contents = String::new();
+        if let Some(dark) = self.last_dark_mode {
+            contents.push_str(&format!("last_dark_mode={}\n", dark));
+        }
+        contents.push_str(&format!("positions={}\n", format_positions(&self.positions)));
+        contents.push_str(match self.rate_unit {
+            RateUnit::Cps => "rate_unit=cps\n",
+            RateUnit::Cpm => "rate_unit=cpm\n",
+        });
+        contents.push_str(&format!("cumulative_clicks={}\n", self.cumulative_clicks));
+        contents.push_str(&format!("cumulative_target_enabled={}\n", self.cumulative_target_enabled));
+        contents.push_str(&format!("cumulative_target={}\n", self.cumulative_target));
+        for (name, positions) in &self.position_sets {
+            contents.push_str(&format!("position_set.{}={}\n", name, format_positions(positions)));
+        }
+        
+        if let Some(dir) = path.parent() {
+            let _ = fs::create_dir_all(dir);
+        }
+        if let Err(e) = fs::write(&path, contents) {
+            println!("Failed to save settings to {}: {}", path.display(), e); // Debug
+        }
+    }
+}
+
+// Bounds of every monitor currently attached, in virtual-screen coordinates
+fn monitor_rects() -> Vec<RECT> {
+    unsafe extern "system" fn collect_rect(_monitor: HMONITOR, _hdc: HDC, rect: *mut RECT, data: LPARAM) -> BOOL {
+        let rects = &mut *(data.0 as *mut Vec<RECT>);
+        rects.push(*rect);
+        BOOL::from(true)
+    }
+    
+    let mut rects: Vec<RECT> = Vec::new();
+    unsafe {
+        let _ = EnumDisplayMonitors(
+            HDC(ptr::null_mut()),
+            None,
+            Some(collect_rect),
+            LPARAM(&mut rects as *mut Vec<RECT> as isize)
+        );
+    }
+    rects
+}
+
+// Returns the point unchanged if it's on a monitor, otherwise the closest on-screen point
+fn clamp_to_monitors(monitors: &[RECT], (x, y): (i32, i32)) -> (i32, i32) {
+    let mut best = (x, y);
+    let mut best_distance = i64::MAX;
+    
+    for rect in monitors {
+        let clamped_x = x.clamp(rect.left, rect.right - 1);
+        let clamped_y = y.clamp(rect.top, rect.bottom - 1);
+        let dx = (clamped_x - x) as i64;
+        let dy = (clamped_y - y) as i64;
+        let distance = dx * dx + dy * dy;
+        if distance < best_distance {
+            best = (clamped_x, clamped_y);
+            best_distance = distance;
+        }
+    }
+    best
+}
+
+// Clamp configured targets that fell off the current monitor layout (e.g. a
+// disconnected display) and describe each adjustment for the UI
+fn validate_click_positions(config: &mut ClickingConfig) -> Vec<String> {
+    let mut warnings = Vec::new();
+    let monitors = monitor_rects();
+    if monitors.is_empty() {
+        return warnings; // Can't tell, leave positions alone
+    }
+    
+    if config.cursor_mode == CursorMode::Fixed {
+        let point = (config.cursor_x, config.cursor_y);
+        let clamped = clamp_to_monitors(&monitors, point);
+        if clamped != point {
+            warnings.push(format!("Fixed position {:?} is off-screen, using {:?}", point, clamped));
+            (config.cursor_x, config.cursor_y) = clamped;
+        }
+    }
+    
+    if let Some(point) = config.initial_position {
+        let clamped = clamp_to_monitors(&monitors, point);
+        if clamped != point {
+            warnings.push(format!("First click position {:?} is off-screen, using {:?}", point, clamped));
+            config.initial_position = Some(clamped);
+        }
+    }
+    
+    if config.cursor_mode == CursorMode::List {
+        for (i, point) in config.positions.iter_mut().enumerate() {
+            let clamped = clamp_to_monitors(&monitors, *point);
+            if clamped != *point {
+                warnings.push(format!("Point {} {:?} is off-screen, using {:?}", i + 1, point, clamped));
+                *point = clamped;
+            }
+        }
+    }
+    
+    warnings
+}
+
+// Effective DPI and bounds of the monitor containing a point
+fn monitor_dpi_at((x, y): (i32, i32)) -> Option<(u32, RECT)> {
+    unsafe {
+        let monitor = MonitorFromPoint(POINT { x, y }, MONITOR_DEFAULTTONULL);
+        if monitor.is_invalid() {
+            return None;
+        }
+        
+        let mut dpi_x = 0;
+        let mut dpi_y = 0;
+        GetDpiForMonitor(monitor, MDT_EFFECTIVE_DPI, &mut dpi_x, &mut dpi_y).ok()?;
+        
+        let mut info = MONITORINFO {
+            cbSize: std::mem::size_of::<MONITORINFO>() as u32,
+            ..Default::default()
+        };
+        if !GetMonitorInfoW(monitor, &mut info).as_bool() {
+            return None;
+        }
+        Some((dpi_x, info.rcMonitor))
+    }
+}
+
+// eframe runs per-monitor DPI aware, so SetCursorPos takes physical pixels. When a
+// monitor's scaling changes, the content under a configured point moves in physical
+// terms; this keeps each target at the same logical offset from its monitor's corner.
+struct DpiTracker {
+    start_dpis: HashMap<(i32, i32), u32>,
+}
+
+impl DpiTracker {
+    fn new(config: &ClickingConfig) -> Self {
+        let mut points = config.positions.clone();
+        points.push((config.cursor_x, config.cursor_y));
+        points.extend(config.initial_position);
+        
+        let start_dpis = points.into_iter()
+            .filter_map(|point| monitor_dpi_at(point).map(|(dpi, _)| (point, dpi)))
+            .collect();
+        Self { start_dpis }
+    }
+    
+    // Returns the physical point to use now, and whether scaling changed since session start
+    fn adjust(&self, point: (i32, i32)) -> ((i32, i32), bool) {
+        let Some(&start_dpi) = self.start_dpis.get(&point) else {
+            return (point, false);
+        };
+        let Some((dpi, monitor)) = monitor_dpi_at(point) else {
+            return (point, false);
+        };
+        if dpi == start_dpi || start_dpi == 0 {
+            return (point, false);
+        }
+        
+        let scale = dpi as f64 / start_dpi as f64;
+        let x = monitor.left + ((point.0 - monitor.left) as f64 * scale).round() as i32;
+        let y = monitor.top + ((point.1 - monitor.top) as f64 * scale).round() as i32;
+        ((x.clamp(monitor.left, monitor.right - 1), y.clamp(monitor.top, monitor.bottom - 1)), true)
+    }
+}
+
+fn window_hwnd(frame: &eframe::Frame) -> Option<HWND> {
+    let handle = frame.window_handle().ok()?;
+    let RawWindowHandle::Win32(handle) = handle.as_raw() else {
+        return None;
+    };
+    Some(HWND(handle.hwnd.get() as *mut std::ffi::c_void))
+}
+
+// egui has no window opacity command, so the toolbar's opacity and click-through are
+// set directly on the window's extended styles. Re-applied each frame in toolbar mode
+// because winit rewrites the styles when decorations or the window level change.
+fn apply_window_overlay(frame: &eframe::Frame, opacity: f32, click_through: bool) {
+    let Some(hwnd) = window_hwnd(frame) else {
+        return;
+    };
+    
+    unsafe {
+        let ex_style = GetWindowLongPtrW(hwnd, GWL_EXSTYLE);
+        let mut wanted = ex_style | WS_EX_LAYERED.0 as isize;
+        if click_through {
+            wanted |= WS_EX_TRANSPARENT.0 as isize;
+        } else {
+            wanted &= !(WS_EX_TRANSPARENT.0 as isize);
+        }
+        if wanted != ex_style {
+            SetWindowLongPtrW(hwnd, GWL_EXSTYLE, wanted);
+        }
+        
+        let alpha = (opacity.clamp(TOOLBAR_MIN_OPACITY, 1.0) * 255.0).round() as u8;
+        let _ = SetLayeredWindowAttributes(hwnd, COLORREF(0), alpha, LWA_ALPHA);
+    }
+}
+
+// Drop the toolbar styles so the full window is a normal, non-layered window again
+fn clear_window_overlay(frame: &eframe::Frame) {
+    let Some(hwnd) = window_hwnd(frame) else {
+        return;
+    };
+    
+    unsafe {
+        let ex_style = GetWindowLongPtrW(hwnd, GWL_EXSTYLE);
+        let wanted = ex_style & !(WS_EX_LAYERED.0 as isize | WS_EX_TRANSPARENT.0 as isize);
+        if wanted != ex_style {
+            SetWindowLongPtrW(hwnd, GWL_EXSTYLE, wanted);
         }
     }
 }
@@ -149,52 +689,120 @@ struct ClickingConfig {
     mouse_button: MouseButton,
     click_type: String,
     click_mode: ClickMode,
-    use_current_position: bool,
+    cursor_mode: CursorMode,
     cursor_x: i32,
     cursor_y: i32,
+    positions: Vec<(i32, i32)>,
+    initial_position: Option<(i32, i32)>, // Overrides the target of the first click only
     random_offset: bool,
     random_offset_ms: u32,
+    on_start_actions: Vec<HookAction>,
+    on_stop_actions: Vec<HookAction>,
+    simulate: bool, // Run the loop's timing without moving the cursor or clicking (benchmark)
+}
+
+impl ClickingConfig {
+    // Fixed sleeps the clicking loop adds to every click on top of the interval:
+    // the cursor settle after SetCursorPos and the gap inside a manual double-click
+    fn fixed_sleep_ms(&self) -> u64 {
+        let moves_cursor = match self.cursor_mode {
+            CursorMode::Current => false,
+            CursorMode::Fixed => true,
+            CursorMode::List => !self.positions.is_empty(),
+        };
+        let settle_ms = if moves_cursor { 10 } else { 0 };
+        let double_gap_ms = if self.click_type == "Double" { 10 } else { 0 };
+        settle_ms + double_gap_ms
+    }
 }
 
 #[derive(Clone)]
 struct ClickerState {
     is_running: Arc<Mutex<bool>>,
+    stop_requested: Arc<Mutex<bool>>, // Set by stop, is_running clears once the on-stop hook is done
     click_count: Arc<Mutex<u32>>,
     should_start: Arc<Mutex<bool>>,
     should_stop: Arc<Mutex<bool>>,
+    should_toggle_toolbar: Arc<Mutex<bool>>,
     hotkey_thread_running: Arc<Mutex<bool>>,
     clicking_config: Arc<Mutex<Option<ClickingConfig>>>,
+    latest_config: Arc<Mutex<Option<ClickingConfig>>>, // UI settings as of the last frame, for hotkey starts
+    position_warnings: Arc<Mutex<Vec<String>>>,
+    click_times: Arc<Mutex<VecDeque<Instant>>>,
+    session_id: Arc<Mutex<u64>>,
+    heartbeat_deadline: Arc<Mutex<Option<Instant>>>,
+    cumulative_clicks: Arc<Mutex<u64>>,
+    cumulative_target: Arc<Mutex<Option<u64>>>, // Stop once the cross-session total reaches this
+    cumulative_target_reached: Arc<Mutex<bool>>,
+    dpi_warning: Arc<Mutex<Option<String>>>,
 }
 
 impl ClickerState {
     fn new() -> Self {
         Self {
             is_running: Arc::new(Mutex::new(false)),
+            stop_requested: Arc::new(Mutex::new(false)),
             click_count: Arc::new(Mutex::new(0)),
             should_start: Arc::new(Mutex::new(false)),
             should_stop: Arc::new(Mutex::new(false)),
+            should_toggle_toolbar: Arc::new(Mutex::new(false)),
             hotkey_thread_running: Arc::new(Mutex::new(false)),
             clicking_config: Arc::new(Mutex::new(None)),
+            latest_config: Arc::new(Mutex::new(None)),
+            position_warnings: Arc::new(Mutex::new(Vec::new())),
+            click_times: Arc::new(Mutex::new(VecDeque::new())),
+            session_id: Arc::new(Mutex::new(0)),
+            heartbeat_deadline: Arc::new(Mutex::new(None)),
+            cumulative_clicks: Arc::new(Mutex::new(0)),
+            cumulative_target: Arc::new(Mutex::new(None)),
+            cumulative_target_reached: Arc::new(Mutex::new(false)),
+            dpi_warning: Arc::new(Mutex::new(None)),
         }
     }
     
-    fn start_clicking_with_config(&self, config: ClickingConfig) {
+    fn start_clicking_with_config(&self, mut config: ClickingConfig) {
         if *self.is_running.lock().unwrap() {
             return; // Already running
         }
         
+        let warnings = validate_click_positions(&mut config);
+        for warning in &warnings {
+            println!("{}", warning); // Debug
+        }
+        *self.position_warnings.lock().unwrap() = warnings;
+        
         *self.is_running.lock().unwrap() = true;
+        *self.stop_requested.lock().unwrap() = false;
         *self.click_count.lock().unwrap() = 0;
+        *self.cumulative_target_reached.lock().unwrap() = false;
+        *self.dpi_warning.lock().unwrap() = None;
+        self.click_times.lock().unwrap().clear();
         *self.clicking_config.lock().unwrap() = Some(config.clone());
         
         println!("Starting clicking with config!"); // Debug
         
+        // A force-stopped thread keeps its old session id. Its state updates run under the
+        // session lock via with_session, so they can't land in a newer session; a click
+        // already past the last check when it unblocks may still be sent once.
+        let session = {
+            let mut session_id = self.session_id.lock().unwrap();
+            *session_id += 1;
+            *session_id
+        };
+        
         let clicker_state = self.clone();
         
         thread::spawn(move || {
             let mut clicks_performed = 0;
+            let mut list_index = 0; // Only advances on list clicks, so the first-click override doesn't skip a point
+            let dpi_tracker = DpiTracker::new(&config);
             
-            while *clicker_state.is_running.lock().unwrap() {
+            clicker_state.beat(session, hook_actions_duration(&config.on_start_actions));
+            run_hook_actions(&config.on_start_actions);
+            
+            while !*clicker_state.stop_requested.lock().unwrap() && clicker_state.is_current_session(session) {
+                clicker_state.beat(session, Duration::ZERO);
+                
                 // Check if we should stop based on repeat count
                 if let ClickMode::RepeatCount(max_clicks) = config.click_mode {
                     if clicks_performed >= max_clicks {
@@ -202,40 +810,107 @@ impl ClickerState {
                     }
                 }
                 
+                // Check the cross-session goal, read live so changes apply mid-session and to hotkey starts
+                let cumulative_target = *clicker_state.cumulative_target.lock().unwrap();
+                if let Some(target) = cumulative_target {
+                    if *clicker_state.cumulative_clicks.lock().unwrap() >= target {
+                        *clicker_state.cumulative_target_reached.lock().unwrap() = true;
+                        println!("Cumulative goal of {} clicks reached!", target); // Debug
+                        unsafe {
+                            let _ = MessageBeep(MB_ICONASTERISK);
+                        }
+                        break;
+                    }
+                }
+                
                 // Set cursor position if needed
                 unsafe {
-                    if !config.use_current_position {
-                        let _ = SetCursorPos(config.cursor_x, config.cursor_y);
+                    let is_override_click = clicks_performed == 0 && config.initial_position.is_some();
+                    
+                    // In Current mode, put the cursor back after the override click
+                    let mut restore_point = None;
+                    if is_override_click && config.cursor_mode == CursorMode::Current && !config.simulate {
+                        let mut point = POINT::default();
+                        if GetCursorPos(&mut point).is_ok() {
+                            restore_point = Some((point.x, point.y));
+                        }
+                    }
+                    
+                    let target = match config.cursor_mode {
+                        _ if is_override_click => config.initial_position,
+                        CursorMode::Current => None,
+                        CursorMode::Fixed => Some((config.cursor_x, config.cursor_y)),
+                        CursorMode::List if config.positions.is_empty() => None,
+                        CursorMode::List => {
+                            let point = config.positions[list_index % config.positions.len()];
+                            list_index += 1;
+                            Some(point)
+                        }
+                    };
+                    if let Some(point) = target {
+                        let ((x, y), rescaled) = dpi_tracker.adjust(point);
+                        if rescaled {
+                            clicker_state.set_dpi_warning("Scaling changed on a target monitor during this session, positions were rescaled");
+                        }
+                        if !config.simulate {
+                            let _ = SetCursorPos(x, y);
+                        }
                         thread::sleep(Duration::from_millis(10));
                     }
                     
+                    // SetCursorPos may be where a hung thread was stuck
+                    if !clicker_state.is_current_session(session) {
+                        break;
+                    }
+                    
                     // Perform click
-                    match config.mouse_button {
-                        MouseButton::Left => {
-                            let _ = mouse_event(MOUSEEVENTF_LEFTDOWN, 0, 0, 0, 0);
-                            let _ = mouse_event(MOUSEEVENTF_LEFTUP, 0, 0, 0, 0);
-                            
-                            if config.click_type == "Double" {
-                                thread::sleep(Duration::from_millis(10));
+                    if config.simulate {
+                        // No input, but keep the same fixed sleeps so the benchmark times the real loop
+                        if config.click_type == "Double" {
+                            thread::sleep(Duration::from_millis(10));
+                        }
+                    } else if config.click_type == "Double (OS)" {
+                        send_os_double_click(config.mouse_button);
+                    } else {
+                        match config.mouse_button {
+                            MouseButton::Left => {
                                 let _ = mouse_event(MOUSEEVENTF_LEFTDOWN, 0, 0, 0, 0);
                                 let _ = mouse_event(MOUSEEVENTF_LEFTUP, 0, 0, 0, 0);
+                                
+                                if config.click_type == "Double" {
+                                    thread::sleep(Duration::from_millis(10));
+                                    let _ = mouse_event(MOUSEEVENTF_LEFTDOWN, 0, 0, 0, 0);
+                                    let _ = mouse_event(MOUSEEVENTF_LEFTUP, 0, 0, 0, 0);
+                                }
                             }
-                        }
-                        MouseButton::Right => {
-                            let _ = mouse_event(MOUSEEVENTF_RIGHTDOWN, 0, 0, 0, 0);
-                            let _ = mouse_event(MOUSEEVENTF_RIGHTUP, 0, 0, 0, 0);
-                            
-                            if config.click_type == "Double" {
-                                thread::sleep(Duration::from_millis(10));
+                            MouseButton::Right => {
                                 let _ = mouse_event(MOUSEEVENTF_RIGHTDOWN, 0, 0, 0, 0);
                                 let _ = mouse_event(MOUSEEVENTF_RIGHTUP, 0, 0, 0, 0);
+                                
+                                if config.click_type == "Double" {
+                                    thread::sleep(Duration::from_millis(10));
+                                    let _ = mouse_event(MOUSEEVENTF_RIGHTDOWN, 0, 0, 0, 0);
+                                    let _ = mouse_event(MOUSEEVENTF_RIGHTUP, 0, 0, 0, 0);
+                                }
                             }
                         }
                     }
+                    
+                    if let Some((x, y)) = restore_point {
+                        thread::sleep(Duration::from_millis(10));
+                        let _ = SetCursorPos(x, y);
+                    }
                 }
                 
+                let counted = clicker_state.with_session(session, || {
+                    *clicker_state.click_count.lock().unwrap() += 1;
+                    *clicker_state.cumulative_clicks.lock().unwrap() += 1;
+                    clicker_state.record_click_time();
+                });
+                if counted.is_none() {
+                    break;
+                }
                 clicks_performed += 1;
-                *clicker_state.click_count.lock().unwrap() += 1;
                 
                 // Calculate sleep duration with optional random offset
                 let mut sleep_duration = config.interval_ms;
@@ -244,27 +919,163 @@ impl ClickerState {
                     sleep_duration = sleep_duration.saturating_add(offset as u64);
                 }
                 
+                clicker_state.beat(session, Duration::from_millis(sleep_duration));
                 thread::sleep(Duration::from_millis(sleep_duration));
             }
             
-            *clicker_state.is_running.lock().unwrap() = false;
+            if !clicker_state.is_current_session(session) {
+                println!("Abandoned clicking thread exited"); // Debug
+                return;
+            }
+            
+            // Every stop path (button, hotkey, repeat count) ends the loop above. is_running
+            // stays set until the hook is done so no new session can start underneath it.
+            clicker_state.beat(session, hook_actions_duration(&config.on_stop_actions));
+            run_hook_actions(&config.on_stop_actions);
+            
+            clicker_state.with_session(session, || {
+                *clicker_state.is_running.lock().unwrap() = false;
+                *clicker_state.stop_requested.lock().unwrap() = false;
+                *clicker_state.heartbeat_deadline.lock().unwrap() = None;
+            });
             println!("Clicking thread stopped!"); // Debug
         });
     }
     
     fn stop_clicking(&self) {
-        *self.is_running.lock().unwrap() = false;
+        // A stuck thread would never see the request, so abandon it instead
+        if self.is_stalled() {
+            self.force_stop();
+            return;
+        }
+        *self.stop_requested.lock().unwrap() = true;
         println!("Requested clicking stop!"); // Debug
     }
     
+    // Abandon a clicking thread that stopped responding and reset state
+    fn force_stop(&self) {
+        let mut session_id = self.session_id.lock().unwrap();
+        *session_id += 1;
+        *self.is_running.lock().unwrap() = false;
+        *self.stop_requested.lock().unwrap() = false;
+        *self.heartbeat_deadline.lock().unwrap() = None;
+        drop(session_id);
+        println!("Force stopped clicking thread!"); // Debug
+    }
+    
     fn is_running(&self) -> bool {
         *self.is_running.lock().unwrap()
     }
     
+    // Stop was requested but the on-stop hook hasn't finished yet
+    fn is_stopping(&self) -> bool {
+        self.is_running() && *self.stop_requested.lock().unwrap()
+    }
+    
+    fn is_current_session(&self, session: u64) -> bool {
+        *self.session_id.lock().unwrap() == session
+    }
+    
+    // Called by the clicking thread before each step that may take `expected`
+    fn beat(&self, session: u64, expected: Duration) {
+        self.with_session(session, || {
+            let deadline = Instant::now() + expected + Duration::from_secs(WATCHDOG_GRACE_SECS);
+            *self.heartbeat_deadline.lock().unwrap() = Some(deadline);
+        });
+    }
+    
+    // Runs `update` only if `session` is still current, holding the session lock
+    // throughout so a force stop can't slip in between the check and the update.
+    // `update` must not take the session lock itself.
+    fn with_session<R>(&self, session: u64, update: impl FnOnce() -> R) -> Option<R> {
+        let session_id = self.session_id.lock().unwrap();
+        if *session_id != session {
+            return None;
+        }
+        Some(update())
+    }
+    
+    fn is_stalled(&self) -> bool {
+        self.is_running() && self.heartbeat_deadline.lock().unwrap().is_some_and(|deadline| Instant::now() > deadline)
+    }
+    
     fn get_click_count(&self) -> u32 {
         *self.click_count.lock().unwrap()
     }
     
+    fn get_cumulative_clicks(&self) -> u64 {
+        *self.cumulative_clicks.lock().unwrap()
+    }
+    
+    fn set_cumulative_clicks(&self, clicks: u64) {
+        *self.cumulative_clicks.lock().unwrap() = clicks;
+    }
+    
+    fn set_cumulative_target(&self, target: Option<u64>) {
+        *self.cumulative_target.lock().unwrap() = target;
+    }
+    
+    fn is_cumulative_target_reached(&self) -> bool {
+        *self.cumulative_target_reached.lock().unwrap()
+    }
+    
+    fn set_dpi_warning(&self, warning: &str) {
+        let mut dpi_warning = self.dpi_warning.lock().unwrap();
+        if dpi_warning.is_none() {
+            println!("{}", warning); // Debug
+            *dpi_warning = Some(warning.to_string());
+        }
+    }
+    
+    fn get_dpi_warning(&self) -> Option<String> {
+        self.dpi_warning.lock().unwrap().clone()
+    }
+    
+    fn record_click_time(&self) {
+        let now = Instant::now();
+        let mut click_times = self.click_times.lock().unwrap();
+        click_times.push_back(now);
+        while let Some(&oldest) = click_times.front() {
+            if now.duration_since(oldest) <= Duration::from_secs(RATE_WINDOW_SECS) {
+                break;
+            }
+            click_times.pop_front();
+        }
+    }
+    
+    // Live clicks per second, None when stopped. Uses the average interval over the
+    // timestamp window, or the configured interval before there are two clicks, and
+    // decays once the time since the last click grows past that interval.
+    fn clicks_per_second(&self) -> Option<f64> {
+        if !self.is_running() {
+            return None;
+        }
+        
+        let configured_interval = self.clicking_config.lock().unwrap()
+            .as_ref()
+            .map(|config| config.interval_ms as f64 / 1000.0)
+            .unwrap_or(0.0);
+        
+        let click_times = self.click_times.lock().unwrap();
+        let (Some(first), Some(last)) = (click_times.front(), click_times.back()) else {
+            return Some(0.0);
+        };
+        
+        let average_interval = if click_times.len() >= 2 {
+            last.duration_since(*first).as_secs_f64() / (click_times.len() - 1) as f64
+        } else {
+            configured_interval
+        };
+        let since_last = Instant::now().duration_since(*last).as_secs_f64();
+        let interval = average_interval.max(since_last);
+        
+        Some(if interval > 0.0 { 1.0 / interval } else { 0.0 })
+    }
+    
+    fn get_position_warnings(&self) -> Vec<String> {
+        self.position_warnings.lock().unwrap().clone()
+    }
+    
     fn request_start(&self) {
         *self.should_start.lock().unwrap() = true;
     }
@@ -273,6 +1084,28 @@ impl ClickerState {
         *self.should_stop.lock().unwrap() = true;
     }
     
+    fn publish_config(&self, config: ClickingConfig) {
+        *self.latest_config.lock().unwrap() = Some(config);
+    }
+    
+    fn get_latest_config(&self) -> Option<ClickingConfig> {
+        self.latest_config.lock().unwrap().clone()
+    }
+    
+    fn request_toggle_toolbar(&self) {
+        *self.should_toggle_toolbar.lock().unwrap() = true;
+    }
+    
+    fn check_and_clear_toolbar_request(&self) -> bool {
+        let mut should_toggle = self.should_toggle_toolbar.lock().unwrap();
+        if *should_toggle {
+            *should_toggle = false;
+            true
+        } else {
+            false
+        }
+    }
+    
     fn check_and_clear_start_request(&self) -> bool {
         let mut should_start = self.should_start.lock().unwrap();
         if *should_start {
@@ -316,7 +1149,8 @@ impl GlobalHotkeyThread {
         }
     }
     
-    fn start(&self, start_mod: ModifierKey, start_key: FunctionKey, stop_mod: ModifierKey, stop_key: FunctionKey, clicker_state: ClickerState, clicking_config: ClickingConfig) {
+    #[allow(clippy::too_many_arguments)]
+    fn start(&self, start_mod: ModifierKey, start_key: FunctionKey, stop_mod: ModifierKey, stop_key: FunctionKey, toolbar_mod: ModifierKey, toolbar_key: FunctionKey, clicker_state: ClickerState, clicking_config: ClickingConfig) {
         *self.should_stop.lock().unwrap() = false;
         *self.is_running.lock().unwrap() = true;
         
@@ -329,6 +1163,7 @@ impl GlobalHotkeyThread {
             
             let mut f6_was_pressed = false;
             let mut f7_was_pressed = false;
+            let mut toolbar_was_pressed = false;
             let mut last_action_time = Instant::now() - Duration::from_secs(1);
             
             while !*should_stop.lock().unwrap() {
@@ -345,54 +1180,180 @@ impl GlobalHotkeyThread {
                         println!("STOPPED clicking via hotkey"); // Debug
                     } else {
                         // Start clicking directly
-                        clicker_state_for_thread.start_clicking_with_config(clicking_config.clone());
+                        // Prefer what the UI shows now over the snapshot taken when polling started
+                        let config = clicker_state_for_thread.get_latest_config().unwrap_or_else(|| clicking_config.clone());
+                        clicker_state_for_thread.start_clicking_with_config(config);
                         println!("STARTED clicking via hotkey"); // Debug
                     }
                     last_action_time = now;
                 }
-                f6_was_pressed = start_pressed;
+                f6_was_pressed = start_pressed;
+                
+                // Check stop-only hotkey (F7 by default) - only if different from start key
+                if start_key != stop_key || start_mod != stop_mod {
+                    let stop_pressed = stop_mod.is_pressed() && stop_key.is_pressed();
+                    if stop_pressed && !f7_was_pressed && now.duration_since(last_action_time) > debounce_time {
+                        println!("F7 pressed! Stopping via hotkey"); // Debug
+                        clicker_state_for_thread.stop_clicking();
+                        last_action_time = now;
+                    }
+                    f7_was_pressed = stop_pressed;
+                }
+                
+                // Check toolbar toggle hotkey (F8 by default) - only if different from start and stop keys.
+                // The window itself is changed by the UI thread.
+                let toolbar_distinct = (toolbar_key != start_key || toolbar_mod != start_mod)
+                    && (toolbar_key != stop_key || toolbar_mod != stop_mod);
+                if toolbar_distinct {
+                    let toolbar_pressed = toolbar_mod.is_pressed() && toolbar_key.is_pressed();
+                    if toolbar_pressed && !toolbar_was_pressed && now.duration_since(last_action_time) > debounce_time {
+                        println!("Toolbar hotkey pressed!"); // Debug
+                        clicker_state_for_thread.request_toggle_toolbar();
+                        last_action_time = now;
+                    }
+                    toolbar_was_pressed = toolbar_pressed;
+                }
+                
+                thread::sleep(Duration::from_millis(HOTKEY_POLL_INTERVAL_MS));
+            }
+            
+            *is_running.lock().unwrap() = false;
+            println!("Global hotkey thread stopped!"); // Debug
+        });
+        
+        clicker_state.set_hotkey_thread_running(true);
+    }
+    
+    fn stop(&self) {
+        *self.should_stop.lock().unwrap() = true;
+        // Wait a bit for thread to stop
+        for _ in 0..10 {
+            if !*self.is_running.lock().unwrap() {
+                break;
+            }
+            thread::sleep(Duration::from_millis(10));
+        }
+    }
+    
+    fn is_running(&self) -> bool {
+        *self.is_running.lock().unwrap()
+    }
+}
+
+#[derive(Clone, Copy)]
+struct BenchmarkStep {
+    interval_ms: u64,
+    expected_ms: f64, // Interval plus the mean random offset and the loop's fixed sleeps
+    mean_ms: f64,
+    jitter_ms: f64, // Standard deviation of the achieved intervals
+    max_ms: f64,
+}
+
+impl BenchmarkStep {
+    fn keeps_up(&self) -> bool {
+        self.mean_ms <= self.expected_ms * (1.0 + BENCHMARK_TOLERANCE)
+    }
+}
+
+// Runs the real clicking loop, with input sending turned off, on a private ClickerState
+#[derive(Clone)]
+struct Benchmark {
+    is_running: Arc<Mutex<bool>>,
+    progress: Arc<Mutex<f32>>,
+    results: Arc<Mutex<Vec<BenchmarkStep>>>,
+}
+
+impl Benchmark {
+    fn new() -> Self {
+        Self {
+            is_running: Arc::new(Mutex::new(false)),
+            progress: Arc::new(Mutex::new(0.0)),
+            results: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+    
+    fn start(&self, config: ClickingConfig) {
+        if *self.is_running.lock().unwrap() {
+            return;
+        }
+        
+        *self.is_running.lock().unwrap() = true;
+        *self.progress.lock().unwrap() = 0.0;
+        self.results.lock().unwrap().clear();
+        
+        let benchmark = self.clone();
+        
+        thread::spawn(move || {
+            for (step, &interval_ms) in BENCHMARK_INTERVALS_MS.iter().enumerate() {
+                // Roughly a second per step, with enough samples for stable stats
+                let samples = (1000 / interval_ms).clamp(20, 200) as u32;
+                
+                let step_config = ClickingConfig {
+                    interval_ms,
+                    click_mode: ClickMode::RepeatCount(samples + 1),
+                    initial_position: None,
+                    on_start_actions: Vec::new(),
+                    on_stop_actions: Vec::new(),
+                    simulate: true,
+                    ..config.clone()
+                };
+                let mut expected_ms = (interval_ms + step_config.fixed_sleep_ms()) as f64;
+                if step_config.random_offset {
+                    expected_ms += step_config.random_offset_ms as f64 / 2.0;
+                }
                 
-                // Check stop-only hotkey (F7 by default) - only if different from start key
-                if start_key != stop_key || start_mod != stop_mod {
-                    let stop_pressed = stop_mod.is_pressed() && stop_key.is_pressed();
-                    if stop_pressed && !f7_was_pressed && now.duration_since(last_action_time) > debounce_time {
-                        println!("F7 pressed! Stopping via hotkey"); // Debug
-                        clicker_state_for_thread.stop_clicking();
-                        last_action_time = now;
-                    }
-                    f7_was_pressed = stop_pressed;
+                let clicker_state = ClickerState::new();
+                clicker_state.start_clicking_with_config(step_config);
+                while clicker_state.is_running() {
+                    thread::sleep(Duration::from_millis(10));
                 }
                 
-                thread::sleep(Duration::from_millis(HOTKEY_POLL_INTERVAL_MS));
+                let click_times = clicker_state.click_times.lock().unwrap().clone();
+                let intervals: Vec<f64> = click_times.iter()
+                    .zip(click_times.iter().skip(1))
+                    .map(|(prev, next)| next.duration_since(*prev).as_secs_f64() * 1000.0)
+                    .collect();
+                if intervals.is_empty() {
+                    continue;
+                }
+                
+                let mean_ms = intervals.iter().sum::<f64>() / intervals.len() as f64;
+                let variance = intervals.iter().map(|ms| (ms - mean_ms).powi(2)).sum::<f64>() / intervals.len() as f64;
+                let max_ms = intervals.iter().cloned().fold(0.0, f64::max);
+                
+                benchmark.results.lock().unwrap().push(BenchmarkStep {
+                    interval_ms,
+                    expected_ms,
+                    mean_ms,
+                    jitter_ms: variance.sqrt(),
+                    max_ms,
+                });
+                *benchmark.progress.lock().unwrap() = (step + 1) as f32 / BENCHMARK_INTERVALS_MS.len() as f32;
             }
             
-            *is_running.lock().unwrap() = false;
-            println!("Global hotkey thread stopped!"); // Debug
+            *benchmark.is_running.lock().unwrap() = false;
+            println!("Benchmark finished!"); // Debug
         });
-        
-        clicker_state.set_hotkey_thread_running(true);
-    }
-    
-    fn stop(&self) {
-        *self.should_stop.lock().unwrap() = true;
-        // Wait a bit for thread to stop
-        for _ in 0..10 {
-            if !*self.is_running.lock().unwrap() {
-                break;
-            }
-            thread::sleep(Duration::from_millis(10));
-        }
     }
     
     fn is_running(&self) -> bool {
         *self.is_running.lock().unwrap()
     }
+    
+    fn get_progress(&self) -> f32 {
+        *self.progress.lock().unwrap()
+    }
+    
+    fn get_results(&self) -> Vec<BenchmarkStep> {
+        self.results.lock().unwrap().clone()
+    }
 }
 
 struct HotkeyManager {
     enabled: bool,
     status: String,
     hotkey_thread: Option<GlobalHotkeyThread>,
+    toolbar_hotkey: Option<(ModifierKey, FunctionKey)>, // Only set while the running thread polls it
 }
 
 impl HotkeyManager {
@@ -401,10 +1362,12 @@ impl HotkeyManager {
             enabled: false,
             status: "Ready to start global hotkey polling".to_string(),
             hotkey_thread: None,
+            toolbar_hotkey: None,
         }
     }
     
-    fn start_polling(&mut self, start_mod: ModifierKey, start_key: FunctionKey, stop_mod: ModifierKey, stop_key: FunctionKey, clicker_state: ClickerState, clicking_config: ClickingConfig) {
+    #[allow(clippy::too_many_arguments)]
+    fn start_polling(&mut self, start_mod: ModifierKey, start_key: FunctionKey, stop_mod: ModifierKey, stop_key: FunctionKey, toolbar_mod: ModifierKey, toolbar_key: FunctionKey, clicker_state: ClickerState, clicking_config: ClickingConfig) {
         // Stop any existing thread
         if let Some(ref thread) = self.hotkey_thread {
             thread.stop();
@@ -412,13 +1375,18 @@ impl HotkeyManager {
         
         // Create and start new thread
         let thread = GlobalHotkeyThread::new();
-        thread.start(start_mod, start_key, stop_mod, stop_key, clicker_state, clicking_config);
+        thread.start(start_mod, start_key, stop_mod, stop_key, toolbar_mod, toolbar_key, clicker_state, clicking_config);
         
         self.hotkey_thread = Some(thread);
+        // Same rule as the polling thread: a toolbar combo shared with Start/Stop or Stop is ignored
+        let toolbar_distinct = (toolbar_mod, toolbar_key) != (start_mod, start_key)
+            && (toolbar_mod, toolbar_key) != (stop_mod, stop_key);
+        self.toolbar_hotkey = toolbar_distinct.then_some((toolbar_mod, toolbar_key));
         self.enabled = true;
-        self.status = format!("✅ Global hotkeys active: {}{} (Start/Stop) | {}{} (Stop)",
+        self.status = format!("✅ Global hotkeys active: {}{} (Start/Stop) | {}{} (Stop) | {}{} (Toolbar)",
             start_mod.to_string(), start_key.to_string(),
-            stop_mod.to_string(), stop_key.to_string());
+            stop_mod.to_string(), stop_key.to_string(),
+            toolbar_mod.to_string(), toolbar_key.to_string());
         
         println!("Hotkey manager started polling"); // Debug
     }
@@ -428,6 +1396,7 @@ impl HotkeyManager {
             thread.stop();
         }
         self.hotkey_thread = None;
+        self.toolbar_hotkey = None;
         self.enabled = false;
         self.status = "Global hotkey polling stopped".to_string();
         println!("Hotkey manager stopped polling"); // Debug
@@ -445,6 +1414,10 @@ impl HotkeyManager {
         }
     }
     
+    fn toolbar_hotkey_active(&self) -> bool {
+        self.toolbar_hotkey.is_some() && self.is_thread_running()
+    }
+    
     fn get_status(&self) -> &str {
         &self.status
     }
@@ -476,12 +1449,25 @@ struct NClickerApp {
     repeat_count: u32,
     
     // Cursor position
-    use_current_position: bool,
+    cursor_mode: CursorMode,
     cursor_x: i32,
     cursor_y: i32,
+    use_initial_position: bool,
+    initial_x: i32,
+    initial_y: i32,
+    show_positions_dialog: bool,
+    position_set_name: String,
+    position_capture_at: Option<Instant>,
     
     // UI Theme
     current_theme: Theme,
+    system_dark_mode: bool,
+    last_theme_check: Instant,
+    theme_changed: Arc<Mutex<bool>>,
+    
+    // Persistence
+    settings: PersistedSettings,
+    last_cumulative_save: Instant,
     
     // Hotkeys
     hotkeys_enabled: bool,
@@ -489,8 +1475,26 @@ struct NClickerApp {
     start_key: FunctionKey,
     stop_modifier: ModifierKey,
     stop_key: FunctionKey,
+    toolbar_modifier: ModifierKey,
+    toolbar_key: FunctionKey,
     show_hotkey_dialog: bool,
     
+    // Floating toolbar
+    toolbar_mode: bool,
+    toolbar_opacity: f32,
+    toolbar_click_through: bool,
+    overlay_applied: bool, // Extended styles were changed and must be cleared on leaving toolbar mode
+
+    
+    // Start/stop hooks
+    on_start_actions: Vec<HookAction>,
+    on_stop_actions: Vec<HookAction>,
+    show_hooks_dialog: bool,
+    
+    // Benchmark
+    benchmark: Benchmark,
+    show_benchmark_dialog: bool,
+    
     // State
     clicker_state: ClickerState,
     hotkey_manager: HotkeyManager,
@@ -498,7 +1502,9 @@ struct NClickerApp {
 
 impl Default for NClickerApp {
     fn default() -> Self {
-        Self {
+        let settings = PersistedSettings::load();
+        
+        let mut app = Self {
             hours: 0,
             minutes: 0,
             seconds: 1,  // Default to 1 second
@@ -509,19 +1515,45 @@ impl Default for NClickerApp {
             click_type: "Single".to_string(),
             click_mode: ClickMode::RepeatUntilStopped,
             repeat_count: 1,
-            use_current_position: true,
+            cursor_mode: CursorMode::Current,
             cursor_x: 0,
             cursor_y: 0,
+            use_initial_position: false,
+            initial_x: 0,
+            initial_y: 0,
+            show_positions_dialog: false,
+            position_set_name: String::new(),
+            position_capture_at: None,
             current_theme: Theme::SystemDefault, // Default to system theme
+            system_dark_mode: settings.last_dark_mode.unwrap_or(false),
+            last_theme_check: Instant::now(),
+            theme_changed: Arc::new(Mutex::new(false)),
+            last_cumulative_save: Instant::now(),
+            settings,
             hotkeys_enabled: true,
             start_modifier: ModifierKey::None,
             start_key: FunctionKey::F6,
             stop_modifier: ModifierKey::None,
             stop_key: FunctionKey::F7,
+            toolbar_modifier: ModifierKey::None,
+            toolbar_key: FunctionKey::F8,
             show_hotkey_dialog: false,
+            toolbar_mode: false,
+            toolbar_opacity: 1.0,
+            toolbar_click_through: false,
+            overlay_applied: false,
+            on_start_actions: Vec::new(),
+            on_stop_actions: Vec::new(),
+            show_hooks_dialog: false,
+            benchmark: Benchmark::new(),
+            show_benchmark_dialog: false,
             clicker_state: ClickerState::new(),
             hotkey_manager: HotkeyManager::new(),
-        }
+        };
+        app.clicker_state.set_cumulative_clicks(app.settings.cumulative_clicks);
+        app.refresh_system_theme();
+        spawn_theme_change_watcher(app.theme_changed.clone());
+        app
     }
 }
 
@@ -540,17 +1572,97 @@ impl NClickerApp {
         format!("{}{}", self.stop_modifier.to_string(), self.stop_key.to_string())
     }
     
+    fn get_toolbar_hotkey_string(&self) -> String {
+        format!("{}{}", self.toolbar_modifier.to_string(), self.toolbar_key.to_string())
+    }
+    
+    fn toolbar_hotkey_conflicts(&self) -> bool {
+        (self.toolbar_modifier, self.toolbar_key) == (self.start_modifier, self.start_key)
+            || (self.toolbar_modifier, self.toolbar_key) == (self.stop_modifier, self.stop_key)
+    }
+    
+    // Based on what the hotkey thread polls, not on unapplied dialog edits
+    fn toolbar_hotkey_usable(&self) -> bool {
+        self.hotkey_manager.toolbar_hotkey_active()
+    }
+    
+    fn rate_text(&self) -> String {
+        let Some(cps) = self.clicker_state.clicks_per_second() else {
+            return "–".to_string();
+        };
+        match self.settings.rate_unit {
+            RateUnit::Cps => format!("{:.2} clicks/s", cps),
+            RateUnit::Cpm => format!("{:.1} clicks/min", cps * 60.0),
+        }
+    }
+    
+    // Switch between the full window and a small borderless always-on-top bar
+    fn set_toolbar_mode(&mut self, ctx: &egui::Context, enabled: bool) {
+        self.toolbar_mode = enabled;
+        
+        let size = egui::Vec2::from(if enabled { TOOLBAR_WINDOW_SIZE } else { MAIN_WINDOW_SIZE });
+        // Relax the bound that would block the resize before changing the size
+        if enabled {
+            ctx.send_viewport_cmd(egui::ViewportCommand::MinInnerSize(size));
+            ctx.send_viewport_cmd(egui::ViewportCommand::InnerSize(size));
+            ctx.send_viewport_cmd(egui::ViewportCommand::MaxInnerSize(size));
+        } else {
+            ctx.send_viewport_cmd(egui::ViewportCommand::MaxInnerSize(size));
+            ctx.send_viewport_cmd(egui::ViewportCommand::InnerSize(size));
+            ctx.send_viewport_cmd(egui::ViewportCommand::MinInnerSize(size));
+        }
+        ctx.send_viewport_cmd(egui::ViewportCommand::Decorations(!enabled));
+        ctx.send_viewport_cmd(egui::ViewportCommand::WindowLevel(if enabled {
+            egui::WindowLevel::AlwaysOnTop
+        } else {
+            egui::WindowLevel::Normal
+        }));
+    }
+    
+    fn show_toolbar(&mut self, ctx: &egui::Context) {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ui.horizontal_centered(|ui| {
+                let handle = ui.add(egui::Label::new("⠿").sense(egui::Sense::drag()))
+                    .on_hover_text("Drag to move");
+                if handle.drag_started_by(egui::PointerButton::Primary) {
+                    ctx.send_viewport_cmd(egui::ViewportCommand::StartDrag);
+                }
+                
+                if self.clicker_state.is_running() {
+                    if ui.button("■ Stop").clicked() {
+                        self.stop_clicking();
+                    }
+                    ui.colored_label(egui::Color32::GREEN, self.rate_text());
+                } else {
+                    if ui.button("▶ Start").clicked() {
+                        self.start_clicking();
+                    }
+                    ui.colored_label(egui::Color32::GRAY, self.rate_text());
+                }
+                
+                if ui.small_button("⛶").on_hover_text(format!("Full window ({})", self.get_toolbar_hotkey_string())).clicked() {
+                    self.set_toolbar_mode(ctx, false);
+                }
+            });
+        });
+    }
+    
     fn get_clicking_config(&self) -> ClickingConfig {
         ClickingConfig {
             interval_ms: self.calculate_interval_ms(),
             mouse_button: self.mouse_button,
             click_type: self.click_type.clone(),
             click_mode: self.click_mode,
-            use_current_position: self.use_current_position,
+            cursor_mode: self.cursor_mode,
             cursor_x: self.cursor_x,
             cursor_y: self.cursor_y,
+            positions: self.settings.positions.clone(),
+            initial_position: self.use_initial_position.then_some((self.initial_x, self.initial_y)),
             random_offset: self.random_offset,
             random_offset_ms: self.random_offset_ms,
+            on_start_actions: self.on_start_actions.clone(),
+            on_stop_actions: self.on_stop_actions.clone(),
+            simulate: false,
         }
     }
     
@@ -564,6 +1676,8 @@ impl NClickerApp {
             self.start_key, 
             self.stop_modifier, 
             self.stop_key, 
+            self.toolbar_modifier, 
+            self.toolbar_key, 
             self.clicker_state.clone(),
             self.get_clicking_config()
         );
@@ -587,10 +1701,55 @@ impl NClickerApp {
         self.clicker_state.stop_clicking();
     }
     
-    fn apply_theme(&self, ctx: &egui::Context) {
+    // Copy the clicking thread's running total into the settings file, throttled while running
+    fn sync_cumulative_clicks(&mut self) {
+        let clicks = self.clicker_state.get_cumulative_clicks();
+        if clicks == self.settings.cumulative_clicks {
+            return;
+        }
+        
+        let throttled = self.clicker_state.is_running()
+            && self.last_cumulative_save.elapsed() < Duration::from_secs(CUMULATIVE_SAVE_INTERVAL_SECS);
+        if !throttled {
+            self.settings.cumulative_clicks = clicks;
+            self.settings.save();
+            self.last_cumulative_save = Instant::now();
+        }
+    }
+    
+    fn reset_cumulative_clicks(&mut self) {
+        self.clicker_state.set_cumulative_clicks(0);
+        self.settings.cumulative_clicks = 0;
+        self.settings.save();
+    }
+    
+    fn refresh_system_theme(&mut self) {
+        self.last_theme_check = Instant::now();
+        
+        match is_windows_dark_mode() {
+            Some(dark) => {
+                self.system_dark_mode = dark;
+                if self.settings.last_dark_mode != Some(dark) {
+                    self.settings.last_dark_mode = Some(dark);
+                    self.settings.save();
+                }
+            }
+            None => {
+                // Keep the last known theme rather than dropping to light
+                println!("Theme detection failed, keeping {} mode", if self.system_dark_mode { "dark" } else { "light" }); // Debug
+            }
+        }
+    }
+    
+    fn apply_theme(&mut self, ctx: &egui::Context) {
         match self.current_theme {
             Theme::SystemDefault => {
-                if is_windows_dark_mode() {
+                let changed = std::mem::take(&mut *self.theme_changed.lock().unwrap());
+                if changed || self.last_theme_check.elapsed() >= Duration::from_secs(THEME_RECHECK_INTERVAL_SECS) {
+                    self.refresh_system_theme();
+                }
+                
+                if self.system_dark_mode {
                     ctx.set_visuals(egui::Visuals::dark());
                 } else {
                     ctx.set_visuals(egui::Visuals::light());
@@ -607,11 +1766,12 @@ impl NClickerApp {
 }
 
 impl eframe::App for NClickerApp {
-    fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+    fn update(&mut self, ctx: &egui::Context, frame: &mut eframe::Frame) {
         // Force regular UI updates even when not focused
         ctx.request_repaint_after(Duration::from_millis(100));
         
         self.apply_theme(ctx);
+        self.sync_cumulative_clicks();
         
         // Start hotkey polling on first frame if enabled
         if self.hotkeys_enabled && !self.hotkey_manager.is_enabled() {
@@ -625,6 +1785,29 @@ impl eframe::App for NClickerApp {
         if self.clicker_state.check_and_clear_stop_request() && self.clicker_state.is_running() {
             self.stop_clicking();
         }
+        if self.clicker_state.check_and_clear_toolbar_request() {
+            self.set_toolbar_mode(ctx, !self.toolbar_mode);
+        }
+        
+        // Hotkey starts pick this up, so settings changed since polling began still apply
+        self.clicker_state.publish_config(self.get_clicking_config());
+        self.clicker_state.set_cumulative_target(
+            self.settings.cumulative_target_enabled.then_some(self.settings.cumulative_target).filter(|&target| target > 0));
+        
+        // Click-through needs the toolbar hotkey to get back out
+        let click_through = self.toolbar_click_through && self.toolbar_hotkey_usable();
+        if self.toolbar_mode {
+            apply_window_overlay(frame, self.toolbar_opacity, click_through);
+            self.overlay_applied = true;
+        } else if self.overlay_applied {
+            clear_window_overlay(frame);
+            self.overlay_applied = false;
+        }
+        
+        if self.toolbar_mode {
+            self.show_toolbar(ctx);
+            return;
+        }
         
         // Show hotkey settings dialog
         if self.show_hotkey_dialog {
@@ -701,6 +1884,51 @@ impl eframe::App for NClickerApp {
                             });
                     });
                     
+                    // Floating toolbar hotkey configuration
+                    ui.horizontal(|ui| {
+                        ui.label("Toolbar:");
+                        egui::ComboBox::from_id_source("toolbar_modifier")
+                            .selected_text(format!("{:?}", self.toolbar_modifier))
+                            .show_ui(ui, |ui| {
+                                ui.selectable_value(&mut self.toolbar_modifier, ModifierKey::None, "None");
+                                ui.selectable_value(&mut self.toolbar_modifier, ModifierKey::Alt, "Alt");
+                                ui.selectable_value(&mut self.toolbar_modifier, ModifierKey::Ctrl, "Ctrl");
+                                ui.selectable_value(&mut self.toolbar_modifier, ModifierKey::Shift, "Shift");
+                                ui.selectable_value(&mut self.toolbar_modifier, ModifierKey::AltCtrl, "Alt+Ctrl");
+                            });
+                        
+                        egui::ComboBox::from_id_source("toolbar_key")
+                            .selected_text(format!("{:?}", self.toolbar_key))
+                            .show_ui(ui, |ui| {
+                                ui.selectable_value(&mut self.toolbar_key, FunctionKey::F1, "F1");
+                                ui.selectable_value(&mut self.toolbar_key, FunctionKey::F2, "F2");
+                                ui.selectable_value(&mut self.toolbar_key, FunctionKey::F3, "F3");
+                                ui.selectable_value(&mut self.toolbar_key, FunctionKey::F4, "F4");
+                                ui.selectable_value(&mut self.toolbar_key, FunctionKey::F5, "F5");
+                                ui.selectable_value(&mut self.toolbar_key, FunctionKey::F6, "F6");
+                                ui.selectable_value(&mut self.toolbar_key, FunctionKey::F7, "F7");
+                                ui.selectable_value(&mut self.toolbar_key, FunctionKey::F8, "F8");
+                                ui.selectable_value(&mut self.toolbar_key, FunctionKey::F9, "F9");
+                                ui.selectable_value(&mut self.toolbar_key, FunctionKey::F10, "F10");
+                                ui.selectable_value(&mut self.toolbar_key, FunctionKey::F11, "F11");
+                                ui.selectable_value(&mut self.toolbar_key, FunctionKey::F12, "F12");
+                            });
+                    });
+                    
+                    if self.toolbar_hotkey_conflicts() {
+                        ui.colored_label(egui::Color32::YELLOW, "⚠️ Toolbar hotkey matches Start/Stop or Stop and is ignored");
+                    }
+                    
+                    ui.horizontal(|ui| {
+                        ui.label("Toolbar opacity:");
+                        ui.add(egui::Slider::new(&mut self.toolbar_opacity, TOOLBAR_MIN_OPACITY..=1.0)
+                            .custom_formatter(|value, _| format!("{:.0}%", value * 100.0)));
+                    });
+                    ui.add_enabled(self.toolbar_hotkey_usable(),
+                        egui::Checkbox::new(&mut self.toolbar_click_through, "Toolbar click-through"))
+                        .on_hover_text("Clicks pass through the toolbar; use the toolbar hotkey to return to the full window")
+                        .on_disabled_hover_text("Needs an applied toolbar hotkey that differs from Start/Stop and Stop");
+                    
                     ui.separator();
                     
                     ui.label(format!("Status: {}", self.hotkey_manager.get_status()));
@@ -745,6 +1973,212 @@ impl eframe::App for NClickerApp {
                 });
         }
         
+        // Show start/stop hooks dialog
+        if self.show_hooks_dialog {
+            egui::Window::new("Start/Stop Actions")
+                .resizable(false)
+                .collapsible(false)
+                .show(ctx, |ui| {
+                    ui.label("When clicking starts:");
+                    edit_hook_actions(ui, "on_start_actions", &mut self.on_start_actions);
+                    
+                    ui.separator();
+                    
+                    ui.label("When clicking stops:");
+                    edit_hook_actions(ui, "on_stop_actions", &mut self.on_stop_actions);
+                    
+                    ui.separator();
+                    
+                    ui.horizontal(|ui| {
+                        if ui.button("OK").clicked() {
+                            self.show_hooks_dialog = false;
+                        }
+                    });
+                    
+                    ui.label("💡 Actions run once around each clicking session");
+                });
+        }
+        
+        // Finish a pending position capture
+        if let Some(capture_at) = self.position_capture_at {
+            if Instant::now() >= capture_at {
+                self.position_capture_at = None;
+                let mut point = POINT::default();
+                if unsafe { GetCursorPos(&mut point) }.is_ok() {
+                    self.settings.positions.push((point.x, point.y));
+                    self.settings.save();
+                }
+            }
+        }
+        
+        // Show positions list dialog
+        if self.show_positions_dialog {
+            // Saved once per finished edit rather than every frame while dragging
+            let mut dirty = false;
+            
+            egui::Window::new("Positions")
+                .resizable(false)
+                .collapsible(false)
+                .show(ctx, |ui| {
+                    let mut remove_index = None;
+                    egui::ScrollArea::vertical().max_height(120.0).show(ui, |ui| {
+                        // Captured points can be negative on monitors left of or above the primary
+                        for (i, (x, y)) in self.settings.positions.iter_mut().enumerate() {
+                            ui.horizontal(|ui| {
+                                ui.label(format!("{}.", i + 1));
+                                ui.label("X:");
+                                let x_response = ui.add(egui::DragValue::new(x).range(i32::MIN..=i32::MAX).speed(1));
+                                ui.label("Y:");
+                                let y_response = ui.add(egui::DragValue::new(y).range(i32::MIN..=i32::MAX).speed(1));
+                                for response in [x_response, y_response] {
+                                    if response.drag_stopped() || response.lost_focus() {
+                                        dirty = true;
+                                    }
+                                }
+                                if ui.small_button("✖").clicked() {
+                                    remove_index = Some(i);
+                                }
+                            });
+                        }
+                    });
+                    if let Some(i) = remove_index {
+                        self.settings.positions.remove(i);
+                        dirty = true;
+                    }
+                    
+                    ui.horizontal(|ui| {
+                        if ui.button("+ Add").clicked() {
+                            self.settings.positions.push((self.cursor_x, self.cursor_y));
+                            dirty = true;
+                        }
+                        
+                        match self.position_capture_at {
+                            Some(capture_at) => {
+                                let remaining = capture_at.saturating_duration_since(Instant::now());
+                                ui.label(format!("Capturing in {:.1}s...", remaining.as_secs_f32()));
+                            }
+                            None => {
+                                if ui.button(format!("Capture ({}s)", POSITION_CAPTURE_DELAY_SECS)).clicked() {
+                                    self.position_capture_at = Some(Instant::now() + Duration::from_secs(POSITION_CAPTURE_DELAY_SECS));
+                                }
+                            }
+                        }
+                        
+                        if ui.button("Clear").clicked() {
+                            self.settings.positions.clear();
+                            dirty = true;
+                        }
+                    });
+                    
+                    ui.separator();
+                    
+                    ui.horizontal(|ui| {
+                        ui.add(egui::TextEdit::singleline(&mut self.position_set_name).hint_text("Set name").desired_width(140.0));
+                        // Names end up as config keys, so keep them to one line without '='
+                        let name = self.position_set_name.trim().to_string();
+                        let valid_name = !name.is_empty() && !name.contains('=') && !name.contains('\n');
+                        if ui.add_enabled(valid_name, egui::Button::new("Save as set")).clicked() {
+                            let positions = self.settings.positions.clone();
+                            match self.settings.position_sets.iter_mut().find(|(n, _)| *n == name) {
+                                Some(set) => set.1 = positions,
+                                None => self.settings.position_sets.push((name, positions)),
+                            }
+                            dirty = true;
+                        }
+                    });
+                    
+                    let mut load_index = None;
+                    let mut delete_index = None;
+                    for (i, (name, positions)) in self.settings.position_sets.iter().enumerate() {
+                        ui.horizontal(|ui| {
+                            ui.label(format!("{} ({} points)", name, positions.len()));
+                            if ui.small_button("Load").clicked() {
+                                load_index = Some(i);
+                            }
+                            if ui.small_button("Delete").clicked() {
+                                delete_index = Some(i);
+                            }
+                        });
+                    }
+                    if let Some(i) = load_index {
+                        self.settings.positions = self.settings.position_sets[i].1.clone();
+                        dirty = true;
+                    }
+                    if let Some(i) = delete_index {
+                        self.settings.position_sets.remove(i);
+                        dirty = true;
+                    }
+                    
+                    ui.separator();
+                    
+                    if ui.button("Close").clicked() {
+                        self.show_positions_dialog = false;
+                        dirty = true; // Catch anything typed into a field that still has focus
+                    }
+                });
+            
+            if dirty {
+                self.settings.save();
+            }
+        }
+        
+        // Show benchmark dialog
+        if self.show_benchmark_dialog {
+            egui::Window::new("Benchmark")
+                .resizable(false)
+                .collapsible(false)
+                .show(ctx, |ui| {
+                    ui.label("Runs the clicking loop with your settings at short intervals (no clicks are sent)");
+                    let fixed_sleep_ms = self.get_clicking_config().fixed_sleep_ms();
+                    if fixed_sleep_ms > 0 {
+                        ui.label(format!("Each click also includes {}ms of fixed delay from your position mode and click type; a step passes when timing stays within {:.0}% of interval + delay",
+                            fixed_sleep_ms, BENCHMARK_TOLERANCE * 100.0));
+                    } else {
+                        ui.label(format!("A step passes when timing stays within {:.0}% of the interval", BENCHMARK_TOLERANCE * 100.0));
+                    }
+                    ui.separator();
+                    
+                    let results = self.benchmark.get_results();
+                    for result in &results {
+                        let text = format!("{:>3}ms → avg {:.2}ms (expected {:.1}ms), jitter ±{:.2}ms, max {:.2}ms",
+                            result.interval_ms, result.mean_ms, result.expected_ms, result.jitter_ms, result.max_ms);
+                        if result.keeps_up() {
+                            ui.colored_label(egui::Color32::GREEN, text);
+                        } else {
+                            ui.colored_label(egui::Color32::from_rgb(255, 165, 0), text);
+                        }
+                    }
+                    
+                    if self.benchmark.is_running() {
+                        ui.add(egui::ProgressBar::new(self.benchmark.get_progress()).show_percentage());
+                    } else if !results.is_empty() {
+                        ui.separator();
+                        // Results run slowest to fastest; only count steps before the first miss
+                        match results.iter().take_while(|result| result.keeps_up()).last() {
+                            Some(best) => {
+                                ui.label(format!("Your system can reliably do ~{:.0} CPS ({}ms interval)",
+                                    1000.0 / best.mean_ms, best.interval_ms));
+                            }
+                            None => {
+                                ui.colored_label(egui::Color32::RED, format!("Timing fell behind even at {}ms", BENCHMARK_INTERVALS_MS[0]));
+                            }
+                        }
+                    }
+                    
+                    ui.separator();
+                    
+                    ui.horizontal(|ui| {
+                        let can_run = !self.benchmark.is_running() && !self.clicker_state.is_running();
+                        if ui.add_enabled(can_run, egui::Button::new("Run")).clicked() {
+                            self.benchmark.start(self.get_clicking_config());
+                        }
+                        if ui.button("Close").clicked() {
+                            self.show_benchmark_dialog = false;
+                        }
+                    });
+                });
+        }
+        
         egui::CentralPanel::default().show(ctx, |ui| {
             ui.spacing_mut().item_spacing.y = 4.0; // Reduce vertical spacing
             ui.spacing_mut().indent = 8.0; // Reduce indentation
@@ -784,9 +2218,20 @@ impl eframe::App for NClickerApp {
                 ui.group(|ui| {
                     ui.spacing_mut().item_spacing.y = 2.0;
                     ui.label("Cursor position");
-                    ui.radio_value(&mut self.use_current_position, true, "Current");
-                    ui.radio_value(&mut self.use_current_position, false, "Fixed");
-                    if !self.use_current_position {
+                    ui.horizontal(|ui| {
+                        ui.radio_value(&mut self.cursor_mode, CursorMode::Current, "Current");
+                        ui.radio_value(&mut self.cursor_mode, CursorMode::Fixed, "Fixed");
+                        ui.radio_value(&mut self.cursor_mode, CursorMode::List, "List");
+                    });
+                    if self.cursor_mode == CursorMode::List {
+                        ui.horizontal(|ui| {
+                            ui.label(format!("{} points", self.settings.positions.len()));
+                            if ui.button("Edit").clicked() {
+                                self.show_positions_dialog = true;
+                            }
+                        });
+                    }
+                    if self.cursor_mode == CursorMode::Fixed {
                         ui.horizontal(|ui| {
                             ui.label("X:");
                             ui.add(egui::DragValue::new(&mut self.cursor_x).range(0..=9999).speed(1));
@@ -794,6 +2239,16 @@ impl eframe::App for NClickerApp {
                             ui.add(egui::DragValue::new(&mut self.cursor_y).range(0..=9999).speed(1));
                         });
                     }
+                    ui.checkbox(&mut self.use_initial_position, "First click at")
+                        .on_hover_text("Send only the first click of a session here, then use the position above");
+                    if self.use_initial_position {
+                        ui.horizontal(|ui| {
+                            ui.label("X:");
+                            ui.add(egui::DragValue::new(&mut self.initial_x).range(i32::MIN..=i32::MAX).speed(1));
+                            ui.label("Y:");
+                            ui.add(egui::DragValue::new(&mut self.initial_y).range(i32::MIN..=i32::MAX).speed(1));
+                        });
+                    }
                 });
             });
             
@@ -820,6 +2275,8 @@ impl eframe::App for NClickerApp {
                             .show_ui(ui, |ui| {
                                 ui.selectable_value(&mut self.click_type, "Single".to_string(), "Single");
                                 ui.selectable_value(&mut self.click_type, "Double".to_string(), "Double");
+                                ui.selectable_value(&mut self.click_type, "Double (OS)".to_string(), "Double (OS)")
+                                    .on_hover_text("Send both clicks in one batch so Windows registers a true double-click");
                             });
                     });
                 });
@@ -837,6 +2294,21 @@ impl eframe::App for NClickerApp {
                         }
                     });
                     ui.radio_value(&mut self.click_mode, ClickMode::RepeatUntilStopped, "Until stopped");
+                    
+                    let target_before = (self.settings.cumulative_target_enabled, self.settings.cumulative_target);
+                    ui.horizontal(|ui| {
+                        ui.checkbox(&mut self.settings.cumulative_target_enabled, "Total goal")
+                            .on_hover_text("Stop once the total across all sessions reaches this many clicks");
+                        if self.settings.cumulative_target_enabled {
+                            if self.settings.cumulative_target == 0 {
+                                self.settings.cumulative_target = 100_000;
+                            }
+                            ui.add(egui::DragValue::new(&mut self.settings.cumulative_target).range(1..=u64::MAX).speed(10));
+                        }
+                    });
+                    if (self.settings.cumulative_target_enabled, self.settings.cumulative_target) != target_before {
+                        self.settings.save();
+                    }
                 });
             });
             
@@ -864,6 +2336,21 @@ impl eframe::App for NClickerApp {
                 if ui.button("Hotkeys").clicked() {
                     self.show_hotkey_dialog = true;
                 }
+                
+                if ui.button("Actions").clicked() {
+                    self.show_hooks_dialog = true;
+                }
+                
+                if ui.button("Bar").on_hover_text(format!("Floating toolbar ({})", self.get_toolbar_hotkey_string())).clicked() {
+                    self.set_toolbar_mode(ctx, true);
+                }
+                
+                if ui.button("Bench").clicked() {
+                    self.show_benchmark_dialog = true;
+                    if !self.clicker_state.is_running() {
+                        self.benchmark.start(self.get_clicking_config());
+                    }
+                }
             });
             
             ui.add_space(4.0);
@@ -871,15 +2358,57 @@ impl eframe::App for NClickerApp {
             
             // Status information - very compact
             ui.horizontal(|ui| {
-                if self.clicker_state.is_running() {
+                if self.clicker_state.is_stopping() {
+                    ui.colored_label(egui::Color32::YELLOW, "● STOPPING");
+                } else if self.clicker_state.is_running() {
                     ui.colored_label(egui::Color32::GREEN, "● RUNNING");
                 } else {
                     ui.colored_label(egui::Color32::RED, "● STOPPED");
                 }
                 ui.label(format!("Clicks: {}", self.clicker_state.get_click_count()));
+                ui.label(format!("Total: {}", self.clicker_state.get_cumulative_clicks()));
+                if ui.small_button("Reset").on_hover_text("Reset the total across sessions").clicked() {
+                    self.reset_cumulative_clicks();
+                }
                 ui.label(format!("Interval: {}ms", self.calculate_interval_ms()));
+                
+                ui.label(format!("Rate: {}", self.rate_text()));
+                let unit_before = self.settings.rate_unit;
+                egui::ComboBox::from_id_source("rate_unit")
+                    .width(50.0)
+                    .selected_text(format!("{:?}", self.settings.rate_unit).to_uppercase())
+                    .show_ui(ui, |ui| {
+                        ui.selectable_value(&mut self.settings.rate_unit, RateUnit::Cps, "CPS");
+                        ui.selectable_value(&mut self.settings.rate_unit, RateUnit::Cpm, "CPM");
+                    });
+                if self.settings.rate_unit != unit_before {
+                    self.settings.save();
+                }
             });
             
+            if self.clicker_state.is_cumulative_target_reached() {
+                ui.colored_label(egui::Color32::GREEN,
+                    format!("🎯 Total goal of {} clicks reached", self.settings.cumulative_target));
+            }
+            
+            if self.clicker_state.is_stalled() {
+                ui.horizontal(|ui| {
+                    ui.colored_label(egui::Color32::RED, "⚠️ Clicking thread appears stuck");
+                    if ui.button("Force stop").clicked() {
+                        self.clicker_state.force_stop();
+                    }
+                });
+            }
+            
+            if let Some(warning) = self.clicker_state.get_dpi_warning() {
+                ui.colored_label(egui::Color32::from_rgb(255, 165, 0), format!("⚠️ {}", warning));
+            }
+            
+            // Off-screen targets found at the start of the last session
+            for warning in self.clicker_state.get_position_warnings() {
+                ui.colored_label(egui::Color32::from_rgb(255, 165, 0), format!("⚠️ {}", warning));
+            }
+            
             // Hotkey status display - compact single line
             if self.hotkeys_enabled && self.hotkey_manager.is_enabled() && self.hotkey_manager.is_thread_running() {
                 ui.colored_label(egui::Color32::GREEN, 
@@ -898,16 +2427,18 @@ impl eframe::App for NClickerApp {
 impl Drop for NClickerApp {
     fn drop(&mut self) {
         self.stop_hotkey_polling();
+        self.settings.cumulative_clicks = self.clicker_state.get_cumulative_clicks();
+        self.settings.save();
     }
 }
 
 fn main() -> Result<(), eframe::Error> {
     let options = eframe::NativeOptions {
         viewport: egui::ViewportBuilder::default()
-            .with_inner_size([560.0, 320.0])  // Taller and slightly wider to fit everything
+            .with_inner_size(MAIN_WINDOW_SIZE)  // Taller and slightly wider to fit everything
             .with_resizable(false)            // Non-resizable
-            .with_min_inner_size([560.0, 320.0])
-            .with_max_inner_size([560.0, 320.0]),
+            .with_min_inner_size(MAIN_WINDOW_SIZE)
+            .with_max_inner_size(MAIN_WINDOW_SIZE),
         ..Default::default()
     };
     
@@ -917,3 +2448,80 @@ fn main() -> Result<(), eframe::Error> {
         Box::new(|_cc| Ok(Box::new(NClickerApp::default()))),
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use windows::Win32::UI::Input::KeyboardAndMouse::{MOUSEEVENTF_MOVE, MOUSEEVENTF_ABSOLUTE};
+    
+    fn rect(left: i32, top: i32, right: i32, bottom: i32) -> RECT {
+        RECT { left, top, right, bottom }
+    }
+    
+    #[test]
+    fn clamp_keeps_point_on_a_monitor() {
+        let monitors = [rect(0, 0, 1920, 1080)];
+        assert_eq!(clamp_to_monitors(&monitors, (0, 0)), (0, 0));
+        assert_eq!(clamp_to_monitors(&monitors, (1919, 1079)), (1919, 1079));
+        assert_eq!(clamp_to_monitors(&monitors, (800, 600)), (800, 600));
+    }
+    
+    #[test]
+    fn clamp_snaps_gap_point_to_nearest_monitor() {
+        // Side by side with a 100px gap, the second monitor shorter than the first
+        let monitors = [rect(0, 0, 1920, 1080), rect(2020, 0, 3300, 720)];
+        assert_eq!(clamp_to_monitors(&monitors, (1950, 500)), (1919, 500));
+        assert_eq!(clamp_to_monitors(&monitors, (2000, 500)), (2020, 500));
+        // Below the short monitor, closer to its bottom edge than to the first monitor
+        assert_eq!(clamp_to_monitors(&monitors, (2500, 900)), (2500, 719));
+    }
+    
+    #[test]
+    fn clamp_keeps_point_on_negative_secondary_monitor() {
+        // Secondary monitor left of and above the primary
+        let monitors = [rect(0, 0, 1920, 1080), rect(-1280, -300, 0, 724)];
+        assert_eq!(clamp_to_monitors(&monitors, (-640, -100)), (-640, -100));
+        assert_eq!(clamp_to_monitors(&monitors, (-1280, -300)), (-1280, -300));
+        // Off the top-left of the secondary snaps onto it, not onto the primary
+        assert_eq!(clamp_to_monitors(&monitors, (-1500, -400)), (-1280, -300));
+    }
+    
+    #[test]
+    fn positions_round_trip_with_negative_coordinates() {
+        let positions = vec![(0, 0), (-1920, 240), (100, -1080), (-5, -7), (2559, 1439)];
+        let formatted = format_positions(&positions);
+        assert_eq!(formatted, "0,0;-1920,240;100,-1080;-5,-7;2559,1439");
+        assert_eq!(parse_positions(&formatted), positions);
+    }
+    
+    #[test]
+    fn parse_positions_skips_malformed_pairs() {
+        assert_eq!(parse_positions(""), Vec::<(i32, i32)>::new());
+        assert_eq!(parse_positions("1,2;oops;3;-4, -5"), vec![(1, 2), (-4, -5)]);
+    }
+    
+    #[test]
+    fn double_click_inputs_are_two_clicks_in_place() {
+        for (button, down, up) in [
+            (MouseButton::Left, MOUSEEVENTF_LEFTDOWN, MOUSEEVENTF_LEFTUP),
+            (MouseButton::Right, MOUSEEVENTF_RIGHTDOWN, MOUSEEVENTF_RIGHTUP),
+        ] {
+            let inputs = double_click_inputs(button);
+            let flags: Vec<MOUSE_EVENT_FLAGS> = inputs.iter()
+                .map(|input| unsafe { input.Anonymous.mi.dwFlags })
+                .collect();
+            assert_eq!(flags, vec![down, up, down, up]);
+            
+            for input in &inputs {
+                assert_eq!(input.r#type, INPUT_MOUSE);
+                let mi = unsafe { input.Anonymous.mi };
+                assert_eq!((mi.dx, mi.dy), (0, 0));
+                // System-stamped at injection, so both clicks land inside GetDoubleClickTime()
+                assert_eq!(mi.time, 0);
+                // No movement between the clicks, so both hit the same coordinate
+                assert!(!mi.dwFlags.contains(MOUSEEVENTF_MOVE));
+                assert!(!mi.dwFlags.contains(MOUSEEVENTF_ABSOLUTE));
+            }
+        }
+    }
+}